@@ -1,20 +1,37 @@
 use std::io;
+use std::io::Write;
+use std::io::{Error, ErrorKind, Result};
 use std::cmp::Ordering;
+use std::str::FromStr;
 
-fn main(){
-    let mut input = String::new();
-    println!("Enter a number: ");
-    io::stdin()
-        .read_line(&mut input).expect("Reading stdin failed");
-    let num1 : i32 = input.trim().parse().expect("Couldn't parse as a number");
-    input.clear();
-    println!("Enter another number: ");
-    io::stdin()
-        .read_line(&mut input).expect("Reading stdin failed");
-    let num2 = input.trim().parse().expect("Couldn't parse as a number");
+fn read_line(prompt: &str) -> Result<String> {
+  print!("{prompt}");
+  io::stdout().flush()?;
+  let mut input = String::new();
+  let bytes = io::stdin().read_line(&mut input)?;
+  if bytes == 0 {
+      return Err(Error::new(ErrorKind::UnexpectedEof, "no input was read"));
+  }
+  Ok(input.trim().to_string())
+}
+
+fn read_parsed<T: FromStr>(prompt: &str) -> Result<T> {
+  loop {
+      let line = read_line(prompt)?;
+      match line.parse::<T>() {
+          Ok(value) => return Ok(value),
+          Err(_) => println!("That wasn't a valid number, try again"),
+      }
+  }
+}
+
+fn main() -> Result<()> {
+    let num1 : i32 = read_parsed("Enter a number: ")?;
+    let num2 : i32 = read_parsed("Enter another number: ")?;
     match num1.cmp(&num2) {
         Ordering::Greater => println!("The first number was bigger"),
         Ordering::Less => println!("The second number was bigger"),
         Ordering::Equal => println!("The two numbers are equal")
     }
+    Ok(())
 }
@@ -0,0 +1,132 @@
+use std::io;
+use std::io::Write;
+use std::io::{Error, ErrorKind, Result};
+use std::str::FromStr;
+use rand::Rng;
+use rand::thread_rng;
+
+fn read_line(prompt: &str) -> Result<String> {
+  print!("{prompt}");
+  io::stdout().flush()?;
+  let mut input = String::new();
+  let bytes = io::stdin().read_line(&mut input)?;
+  if bytes == 0 {
+      return Err(Error::new(ErrorKind::UnexpectedEof, "no input was read"));
+  }
+  Ok(input.trim().to_string())
+}
+
+fn read_parsed<T: FromStr>(prompt: &str) -> Result<T> {
+  loop {
+      let line = read_line(prompt)?;
+      match line.parse::<T>() {
+          Ok(value) => return Ok(value),
+          Err(_) => println!("That wasn't a valid number, try again"),
+      }
+  }
+}
+
+enum GameState {
+    ComeOut,
+    PointRolls,
+    GameOver,
+}
+
+struct CrapsGame {
+    wallet : usize,
+    bet : usize,
+    point : u8,
+    state : GameState,
+}
+
+fn roll_dice() -> u8 {
+    let mut rng = thread_rng();
+    rng.gen_range(1..=6) + rng.gen_range(1..=6)
+}
+
+impl CrapsGame {
+    fn new(wallet: usize) -> CrapsGame {
+        CrapsGame { wallet, bet: 0, point: 0, state: GameState::ComeOut }
+    }
+
+    fn place_bet(&mut self) -> Result<()> {
+        loop {
+            let bet : usize = read_parsed(&format!("You have {} in your wallet, how much do you want to bet?: ", self.wallet))?;
+            if bet == 0 || bet > self.wallet {
+                println!("You need to bet between 1 and {}", self.wallet);
+                continue;
+            }
+            self.bet = bet;
+            return Ok(());
+        }
+    }
+
+    // Plays one come-out or point roll and reports whether play should continue.
+    fn tick(&mut self) -> bool {
+        match self.state {
+            GameState::ComeOut => {
+                let roll = roll_dice();
+                println!("You rolled a {}", roll);
+                match roll {
+                    7 | 11 => {
+                        println!("You win!");
+                        self.wallet += self.bet;
+                        self.state = GameState::ComeOut;
+                    }
+                    2 | 3 | 12 => {
+                        println!("Craps, you lose");
+                        self.wallet -= self.bet;
+                        self.state = GameState::ComeOut;
+                    }
+                    _ => {
+                        println!("Your point is {}", roll);
+                        self.point = roll;
+                        self.state = GameState::PointRolls;
+                    }
+                }
+            }
+            GameState::PointRolls => {
+                let roll = roll_dice();
+                println!("You rolled a {}", roll);
+                if roll == self.point {
+                    println!("You made your point, you win!");
+                    self.wallet += self.bet;
+                    self.state = GameState::ComeOut;
+                } else if roll == 7 {
+                    println!("Seven out, you lose");
+                    self.wallet -= self.bet;
+                    self.state = GameState::ComeOut;
+                }
+            }
+            GameState::GameOver => return false,
+        }
+
+        if self.wallet == 0 {
+            println!("You're out of money, game over");
+            self.state = GameState::GameOver;
+            return false;
+        }
+        true
+    }
+}
+
+fn main() -> Result<()> {
+    println!("Welcome to Craps");
+    let mut game = CrapsGame::new(100);
+    loop {
+        if matches!(game.state, GameState::ComeOut) {
+            game.place_bet()?;
+        }
+        if !game.tick() {
+            break;
+        }
+        if matches!(game.state, GameState::ComeOut) {
+            let again = read_line("Play another round? ")?;
+            if again.eq_ignore_ascii_case("no"){
+                break;
+            }
+        }
+    }
+    println!("You're leaving with {} in your wallet", game.wallet);
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use std::io;
+use std::io::Write;
+use std::io::{Error, ErrorKind, Result};
+use std::cmp::Ordering;
+use std::str::FromStr;
+use rand::Rng;
+use rand::thread_rng;
+
+fn read_line(prompt: &str) -> Result<String> {
+  print!("{prompt}");
+  io::stdout().flush()?;
+  let mut input = String::new();
+  let bytes = io::stdin().read_line(&mut input)?;
+  if bytes == 0 {
+      return Err(Error::new(ErrorKind::UnexpectedEof, "no input was read"));
+  }
+  Ok(input.trim().to_string())
+}
+
+fn read_parsed<T: FromStr>(prompt: &str) -> Result<T> {
+  loop {
+      let line = read_line(prompt)?;
+      match line.parse::<T>() {
+          Ok(value) => return Ok(value),
+          Err(_) => println!("That wasn't a valid number, try again"),
+      }
+  }
+}
+
+fn play_round() -> Result<()> {
+    let secret = thread_rng().gen_range(1..=100);
+    let mut guesses = 0;
+    println!("I've picked a number between 1 and 100, try to guess it");
+    loop {
+        let guess : i32 = read_parsed("Your guess: ")?;
+        guesses += 1;
+        match guess.cmp(&secret) {
+            Ordering::Less => println!("Too small"),
+            Ordering::Greater => println!("Too big"),
+            Ordering::Equal => {
+                println!("You win! It took you {} guesses", guesses);
+                break;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    loop {
+        play_round()?;
+        let again = read_line("Play another round? ")?;
+        if again.eq_ignore_ascii_case("no"){
+            break;
+        }
+    }
+    Ok(())
+}
@@ -1,6 +1,10 @@
+use std::fs;
 use std::io;
 use std::io::Write;
-use std::io::Result;
+use std::io::{Error, ErrorKind, Result};
+use std::str::FromStr;
+
+const PETS_FILE: &str = "pets.txt";
 
 struct Pet {
     name : String,
@@ -8,21 +12,109 @@ struct Pet {
     age : u8,
 }
 
+impl Pet {
+    fn to_line(&self) -> String {
+        format!("{},{},{}", self.name, self.species, self.age)
+    }
+
+    fn from_line(line: &str) -> Option<Pet> {
+        let mut fields = line.splitn(3, ',');
+        let name = fields.next()?.to_string();
+        let species = fields.next()?.to_string();
+        let age : u8 = fields.next()?.parse().ok()?;
+        Some(Pet { name, species, age })
+    }
+}
+
+fn load_pets() -> Vec<Pet> {
+    let contents = match fs::read_to_string(PETS_FILE) {
+        Ok(contents) => contents,
+        Err(_) => {
+            println!("No pets yet");
+            return Vec::new();
+        }
+    };
+
+    let mut pets = Vec::new();
+    for (i, line) in contents.lines().enumerate() {
+        match Pet::from_line(line) {
+            Some(pet) => pets.push(pet),
+            None => println!("Skipping malformed pet record on line {}", i + 1),
+        }
+    }
+    pets
+}
+
+fn save_pets(pets: &[Pet]) -> Result<()> {
+    let contents : String = pets.iter().map(|p| p.to_line() + "\n").collect();
+    fs::write(PETS_FILE, contents)
+}
+
+fn filter_by_species(pets: Vec<Pet>, species: &str) -> Vec<Pet> {
+    if species.is_empty() {
+        pets
+    } else {
+        pets.into_iter().filter(|p| p.species.eq_ignore_ascii_case(species)).collect()
+    }
+}
+
+// Sorts pets youngest-first by picking a pivot and partitioning the rest
+// into "younger-or-equal" and "older" groups, recursing on each.
+fn quicksort_by_age(mut pets: Vec<Pet>) -> Vec<Pet> {
+    if pets.len() <= 1 {
+        return pets;
+    }
+    let pivot = pets.remove(0);
+    let mut younger_or_equal = Vec::new();
+    let mut older = Vec::new();
+    for pet in pets {
+        if pet.age <= pivot.age {
+            younger_or_equal.push(pet);
+        } else {
+            older.push(pet);
+        }
+    }
+    let mut sorted = quicksort_by_age(younger_or_equal);
+    sorted.push(pivot);
+    sorted.extend(quicksort_by_age(older));
+    sorted
+}
+
 fn read_line(prompt: &str) -> Result<String> {
   print!("{prompt}");
   io::stdout().flush()?;
   let mut input = String::new();
-  io::stdin().read_line(&mut input)?;
+  let bytes = io::stdin().read_line(&mut input)?;
+  if bytes == 0 {
+      return Err(Error::new(ErrorKind::UnexpectedEof, "no input was read"));
+  }
   Ok(input.trim().to_string())
 }
 
+fn read_parsed<T: FromStr>(prompt: &str) -> Result<T> {
+  loop {
+      let line = read_line(prompt)?;
+      match line.parse::<T>() {
+          Ok(value) => return Ok(value),
+          Err(_) => println!("That wasn't a valid number, try again"),
+      }
+  }
+}
+
 fn main() -> Result<()>{
-    let mut pets = Vec::new();
+    let mut pets = load_pets();
+    if !pets.is_empty() {
+        println!("Here are the pets you've told us about before:");
+        for p in &pets {
+            println!("{} is a {} and is {} years old", p.name, p.species, p.age);
+        }
+    }
+
     println!("It's time to tell us about some pets you've had");
     loop {
         let name = read_line("What is their name?: ")?;
         let species = read_line("What kind of animal are they?: ")?;
-        let age : u8 = read_line("How old are they?: ")?.parse().expect("Needed a number");
+        let age : u8 = read_parsed("How old are they?: ")?;
         let new_pet = Pet {name: name, species: species, age: age};
         pets.push(new_pet);
 
@@ -32,7 +124,26 @@ fn main() -> Result<()>{
         }
     }
 
-    for p in pets {
+    save_pets(&pets)?;
+
+    let species_filter = read_line("Filter by species? (leave blank for none): ")?;
+    let view = read_line("View by age-youngest, age-oldest, or name?: ")?;
+    let filtered = filter_by_species(pets, &species_filter);
+    let to_show = match view.as_str() {
+        "age-oldest" => {
+            let mut sorted = quicksort_by_age(filtered);
+            sorted.reverse();
+            sorted
+        }
+        "name" => {
+            let mut sorted = filtered;
+            sorted.sort_by(|a, b| a.name.cmp(&b.name));
+            sorted
+        }
+        _ => quicksort_by_age(filtered),
+    };
+
+    for p in to_show {
         println!("{} is a {} and is {} years old",p.name,p.species,p.age);
     }
     Ok(())